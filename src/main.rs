@@ -1,12 +1,36 @@
+use std::collections::HashSet;
 use std::io::{self, Write};
+use std::path::PathBuf;
+use chrono::{NaiveDate, NaiveDateTime};
+use clap::{Parser, Subcommand};
 use colored::Colorize; // Import the Colorize trait
 use prettytable::{Table, row}; // Removed unused `cell`
+use serde::{Deserialize, Serialize};
 
 // Define a Task struct
+//
+// Every field added after the initial `{id, description, completed}` shape
+// carries `#[serde(default)]` so a `tasks.json` written by an older build of
+// this binary still loads — missing fields fall back to their defaults
+// instead of the whole file being rejected as corrupt.
+#[derive(Serialize, Deserialize)]
 struct Task {
     id: usize,
     description: String,
-    completed: bool,
+    #[serde(default)]
+    status: Status,
+    #[serde(default)]
+    when: Option<NaiveDateTime>,
+    #[serde(default)]
+    deadline: Option<NaiveDateTime>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default)]
+    time_entries: Vec<TimeEntry>,
+    #[serde(default)]
+    dependencies: HashSet<usize>,
 }
 
 impl Task {
@@ -14,22 +38,415 @@ impl Task {
         Task {
             id,
             description,
-            completed: false,
+            status: Status::Inbox,
+            when: None,
+            deadline: None,
+            tags: Vec::new(),
+            priority: Priority::Low,
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
         }
     }
 
     fn status(&self) -> String {
-        if self.completed {
-            "✓".green().to_string()
-        } else {
-            " ".yellow().to_string()
+        self.status.render()
+    }
+
+    // Promotes a freshly-created task out of the inbox once it has a
+    // concrete `when` or `deadline`, i.e. it's no longer just an
+    // unscheduled idea but a scheduled pending item.
+    fn promote_if_scheduled(&mut self) {
+        if self.status == Status::Inbox && (self.when.is_some() || self.deadline.is_some()) {
+            self.status = Status::Pending;
+        }
+    }
+
+    fn total_time(&self) -> (u16, u16) {
+        let total_minutes: u32 = self
+            .time_entries
+            .iter()
+            .map(|e| e.hours as u32 * 60 + e.minutes as u32)
+            .sum();
+        ((total_minutes / 60) as u16, (total_minutes % 60) as u16)
+    }
+
+    // Dependency IDs that are still not `Completed`. A task is blocked
+    // while this is non-empty.
+    fn open_dependencies(&self, all_tasks: &[Task]) -> Vec<usize> {
+        let mut open: Vec<usize> = self
+            .dependencies
+            .iter()
+            .copied()
+            .filter(|dep_id| {
+                all_tasks
+                    .iter()
+                    .find(|t| t.id == *dep_id)
+                    .map(|t| t.status != Status::Completed)
+                    .unwrap_or(false)
+            })
+            .collect();
+        open.sort_unstable();
+        open
+    }
+}
+
+// Parses a comma-separated list of task IDs, validating that each one
+// refers to an existing task and that the task doesn't depend on itself.
+fn parse_dependency_input(
+    input: &str,
+    task_id: usize,
+    all_tasks: &[Task],
+) -> Result<HashSet<usize>, String> {
+    let mut dependencies = HashSet::new();
+    for part in input.trim().split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let dep_id: usize = part
+            .parse()
+            .map_err(|_| format!("\"{part}\" is not a valid task ID"))?;
+        if dep_id == task_id {
+            return Err("a task cannot depend on itself".to_string());
+        }
+        if !all_tasks.iter().any(|t| t.id == dep_id) {
+            return Err(format!("task {dep_id} does not exist"));
+        }
+        dependencies.insert(dep_id);
+    }
+    Ok(dependencies)
+}
+
+// A single logged work session against a task, stamped with the date it
+// was logged.
+#[derive(Serialize, Deserialize)]
+struct TimeEntry {
+    logged_date: NaiveDate,
+    hours: u16,
+    minutes: u16,
+}
+
+// Lifecycle a task moves through, GTD-style: it starts in the inbox,
+// gets promoted to a concrete pending item, picked up as started, and
+// finally completed.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+enum Status {
+    #[default]
+    Inbox,
+    Pending,
+    Started,
+    Completed,
+}
+
+impl Status {
+    fn render(&self) -> String {
+        match self {
+            Status::Inbox => "•".white().to_string(),
+            Status::Pending => " ".yellow().to_string(),
+            Status::Started => "●".blue().to_string(),
+            Status::Completed => "✓".green().to_string(),
+        }
+    }
+}
+
+// Urgency of a task. Ordered Low < Medium < High, so a plain sort puts
+// Low first; the priority listing sorts in reverse to show High first.
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+enum Priority {
+    #[default]
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    fn parse(input: &str) -> Option<Priority> {
+        match input.trim().to_lowercase().as_str() {
+            "" | "low" | "l" => Some(Priority::Low),
+            "medium" | "med" | "m" => Some(Priority::Medium),
+            "high" | "h" => Some(Priority::High),
+            _ => None,
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            Priority::Low => "Low".truecolor(0, 200, 0).to_string(),
+            Priority::Medium => "Medium".truecolor(255, 191, 0).to_string(),
+            Priority::High => "High".truecolor(220, 20, 20).to_string(),
+        }
+    }
+}
+
+fn format_tags(tags: &[String]) -> String {
+    if tags.is_empty() {
+        "-".to_string()
+    } else {
+        tags.join(", ")
+    }
+}
+
+fn format_total_time(task: &Task) -> String {
+    let (hours, minutes) = task.total_time();
+    if hours == 0 && minutes == 0 {
+        "-".to_string()
+    } else {
+        format!("{hours}h{minutes:02}m")
+    }
+}
+
+fn format_blocked(task: &Task, all_tasks: &[Task]) -> String {
+    let open = task.open_dependencies(all_tasks);
+    if open.is_empty() {
+        "-".to_string()
+    } else {
+        let ids = open.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ");
+        format!("🚫 blocked on {ids}").red().to_string()
+    }
+}
+
+fn print_tasks_table(tasks: &[&Task], all_tasks: &[Task]) {
+    let mut table = Table::new();
+    table.add_row(row![
+        "ID", "Description", "Tags", "Priority", "Status", "When", "Deadline", "Time Logged", "Blocked"
+    ]);
+    for task in tasks {
+        table.add_row(row![
+            task.id,
+            task.description,
+            format_tags(&task.tags),
+            task.priority.render(),
+            task.status(),
+            format_datetime(&task.when),
+            format_deadline(&task.deadline),
+            format_total_time(task),
+            format_blocked(task, all_tasks)
+        ]);
+    }
+    table.printstd();
+}
+
+fn format_datetime(value: &Option<NaiveDateTime>) -> String {
+    match value {
+        Some(dt) => dt.format("%Y-%m-%d %H:%M").to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn format_deadline(value: &Option<NaiveDateTime>) -> String {
+    match value {
+        Some(dt) => {
+            let text = dt.format("%Y-%m-%d %H:%M").to_string();
+            if *dt < chrono::Local::now().naive_local() {
+                text.red().to_string()
+            } else {
+                text
+            }
+        }
+        None => "-".to_string(),
+    }
+}
+
+// Parses free-text like "tomorrow 5pm" or "next monday" into a concrete
+// datetime. Returns `None` for blank input and an error string for input
+// that `fuzzydate` can't make sense of.
+fn parse_datetime_input(input: &str) -> Result<Option<NaiveDateTime>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    fuzzydate::parse(input)
+        .map(Some)
+        .map_err(|_| format!("could not understand \"{input}\" as a date/time"))
+}
+
+// On-disk representation: the task list plus the next id to hand out, so
+// ids stay stable across sessions instead of restarting from 1 each time.
+#[derive(Serialize, Deserialize)]
+struct Store {
+    next_id: usize,
+    tasks: Vec<Task>,
+}
+
+impl Store {
+    fn data_file() -> PathBuf {
+        let mut dir = dirs::data_dir().unwrap_or_else(|| PathBuf::from("."));
+        dir.push("rust-task-manager");
+        dir.push("tasks.json");
+        dir
+    }
+
+    fn load() -> Store {
+        let path = Self::data_file();
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => match serde_json::from_str(&contents) {
+                Ok(store) => store,
+                Err(_) => {
+                    println!(
+                        "{}",
+                        "⚠️  Saved tasks file is corrupt, starting with an empty list."
+                            .yellow()
+                    );
+                    Store {
+                        next_id: 1,
+                        tasks: Vec::new(),
+                    }
+                }
+            },
+            Err(_) => Store {
+                next_id: 1,
+                tasks: Vec::new(),
+            },
+        }
+    }
+
+    fn save(&self) {
+        let path = Self::data_file();
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                println!("{}", format!("⚠️  Could not create data directory: {e}").yellow());
+                return;
+            }
+        }
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(&path, json) {
+                    println!("{}", format!("⚠️  Could not save tasks: {e}").yellow());
+                }
+            }
+            Err(e) => println!("{}", format!("⚠️  Could not serialize tasks: {e}").yellow()),
+        }
+    }
+
+    fn delete(&mut self, id: usize) -> bool {
+        let len_before = self.tasks.len();
+        self.tasks.retain(|t| t.id != id);
+        self.tasks.len() != len_before
+    }
+}
+
+/// A simple task manager, usable interactively or scripted from the shell.
+#[derive(Parser)]
+#[command(name = "task-manager")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Add a new task
+    Add {
+        description: String,
+        #[arg(long)]
+        due: Option<String>,
+        #[arg(long)]
+        priority: Option<String>,
+        #[arg(long)]
+        tags: Option<String>,
+    },
+    /// List tasks, optionally filtered by tag
+    List {
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Mark a task as completed
+    Complete { id: usize },
+    /// Delete a task
+    Delete { id: usize },
+}
+
+fn run_command(command: Commands) {
+    let mut store = Store::load();
+
+    match command {
+        Commands::Add { description, due, priority, tags } => {
+            let description = description.trim().to_string();
+            if description.is_empty() {
+                println!("{}", "❌ Task description cannot be empty".red());
+                std::process::exit(1);
+            }
+
+            let mut task = Task::new(store.next_id, description);
+
+            if let Some(due) = due {
+                match parse_datetime_input(&due) {
+                    Ok(deadline) => task.deadline = deadline,
+                    Err(e) => {
+                        println!("❌ {e}");
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            task.promote_if_scheduled();
+
+            if let Some(priority) = priority {
+                match Priority::parse(&priority) {
+                    Some(parsed) => task.priority = parsed,
+                    None => {
+                        println!("❌ Unrecognized priority \"{priority}\"");
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            if let Some(tags) = tags {
+                task.tags = tags
+                    .split(',')
+                    .map(|t| t.trim())
+                    .filter(|t| !t.is_empty())
+                    .map(|t| t.to_string())
+                    .collect();
+            }
+
+            println!("✅ Task {} added!", store.next_id);
+            store.tasks.push(task);
+            store.next_id += 1;
+            store.save();
+        }
+        Commands::List { tag } => {
+            let filtered: Vec<&Task> = store
+                .tasks
+                .iter()
+                .filter(|t| match &tag {
+                    Some(tag) => t.tags.iter().any(|existing| existing == tag),
+                    None => true,
+                })
+                .collect();
+
+            if filtered.is_empty() {
+                println!("{}", "📝 No tasks found.".yellow());
+            } else {
+                print_tasks_table(&filtered, &store.tasks);
+            }
+        }
+        Commands::Complete { id } => {
+            if let Some(task) = store.tasks.iter_mut().find(|t| t.id == id) {
+                task.status = Status::Completed;
+                store.save();
+                println!("🎉 Task {} completed!", id);
+            } else {
+                println!("❌ Task {} not found", id);
+                std::process::exit(1);
+            }
+        }
+        Commands::Delete { id } => {
+            if store.delete(id) {
+                store.save();
+                println!("🗑️  Task {} deleted!", id);
+            } else {
+                println!("❌ Task {} not found", id);
+                std::process::exit(1);
+            }
         }
     }
 }
 
 fn main() {
-    let mut tasks: Vec<Task> = Vec::new();
-    let mut next_id = 1;
+    let cli = Cli::parse();
+    if let Some(command) = cli.command {
+        run_command(command);
+        return;
+    }
+
+    let mut store = Store::load();
 
     println!("{}", "🦀 Welcome to Rust Task Manager!".bright_purple().bold());
     println!("{}", "Use the menu to manage tasks.\n".bright_blue());
@@ -39,7 +456,13 @@ fn main() {
         println!("1️⃣  Add task");
         println!("2️⃣  List tasks");
         println!("3️⃣  Complete task");
-        println!("4️⃣  Quit");
+        println!("4️⃣  List tasks by tag");
+        println!("5️⃣  List tasks by priority");
+        println!("6️⃣  Start task");
+        println!("7️⃣  Return task to inbox");
+        println!("8️⃣  Log time against a task");
+        println!("9️⃣  Add dependency to a task");
+        println!("🔟 Quit");
         print!("{}", "> ".bright_green());
         io::stdout().flush().unwrap();
 
@@ -59,21 +482,73 @@ fn main() {
                 if desc.is_empty() {
                     println!("{}", "❌ Task description cannot be empty".red());
                 } else {
-                    tasks.push(Task::new(next_id, desc.to_string()));
-                    println!("✅ Task {} added!", next_id);
-                    next_id += 1;
+                    let mut task = Task::new(store.next_id, desc.to_string());
+
+                    print!("{}", "When (e.g. \"tomorrow 5pm\", blank to skip): ".bright_yellow());
+                    io::stdout().flush().unwrap();
+                    let mut when_input = String::new();
+                    io::stdin().read_line(&mut when_input).unwrap();
+                    match parse_datetime_input(&when_input) {
+                        Ok(when) => task.when = when,
+                        Err(e) => println!("❌ {e}"),
+                    }
+
+                    print!("{}", "Deadline (e.g. \"next monday\", blank to skip): ".bright_yellow());
+                    io::stdout().flush().unwrap();
+                    let mut deadline_input = String::new();
+                    io::stdin().read_line(&mut deadline_input).unwrap();
+                    match parse_datetime_input(&deadline_input) {
+                        Ok(deadline) => task.deadline = deadline,
+                        Err(e) => println!("❌ {e}"),
+                    }
+
+                    task.promote_if_scheduled();
+
+                    print!("{}", "Tags (comma-separated, blank for none): ".bright_yellow());
+                    io::stdout().flush().unwrap();
+                    let mut tags_input = String::new();
+                    io::stdin().read_line(&mut tags_input).unwrap();
+                    task.tags = tags_input
+                        .trim()
+                        .split(',')
+                        .map(|t| t.trim())
+                        .filter(|t| !t.is_empty())
+                        .map(|t| t.to_string())
+                        .collect();
+
+                    print!("{}", "Priority (low/medium/high, blank for low): ".bright_yellow());
+                    io::stdout().flush().unwrap();
+                    let mut priority_input = String::new();
+                    io::stdin().read_line(&mut priority_input).unwrap();
+                    match Priority::parse(&priority_input) {
+                        Some(priority) => task.priority = priority,
+                        None => println!(
+                            "{}",
+                            "❌ Unrecognized priority, defaulting to Low".red()
+                        ),
+                    }
+
+                    print!("{}", "Depends on (comma-separated task IDs, blank for none): ".bright_yellow());
+                    io::stdout().flush().unwrap();
+                    let mut deps_input = String::new();
+                    io::stdin().read_line(&mut deps_input).unwrap();
+                    match parse_dependency_input(&deps_input, task.id, &store.tasks) {
+                        Ok(dependencies) => task.dependencies = dependencies,
+                        Err(e) => println!("❌ {e}"),
+                    }
+
+                    println!("✅ Task {} added!", store.next_id);
+                    store.tasks.push(task);
+                    store.next_id += 1;
+                    store.save();
                 }
             }
             "2" => {
-                if tasks.is_empty() {
+                if store.tasks.is_empty() {
                     println!("{}", "📝 No tasks yet. Add one first.".yellow());
                 } else {
-                    let mut table = Table::new();
-                    table.add_row(row!["ID", "Description", "Status"]);
-                    for task in &tasks {
-                        table.add_row(row![task.id, task.description, task.status()]);
-                    }
-                    table.printstd();
+                    let refs: Vec<&Task> = store.tasks.iter().collect();
+                    print_tasks_table(&refs, &store.tasks);
                 }
             }
             "3" => {
@@ -84,9 +559,10 @@ fn main() {
                 io::stdin().read_line(&mut id_input).unwrap();
 
                 if let Ok(id) = id_input.trim().parse::<usize>() {
-                    if let Some(task) = tasks.iter_mut().find(|t| t.id == id) {
-                        task.completed = true;
+                    if let Some(task) = store.tasks.iter_mut().find(|t| t.id == id) {
+                        task.status = Status::Completed;
                         println!("🎉 Task {} completed!", id);
+                        store.save();
                     } else {
                         println!("❌ Task {} not found", id);
                     }
@@ -95,6 +571,145 @@ fn main() {
                 }
             }
             "4" => {
+                print!("{}", "Enter tag to filter by: ".bright_yellow());
+                io::stdout().flush().unwrap();
+
+                let mut tag_input = String::new();
+                io::stdin().read_line(&mut tag_input).unwrap();
+                let tag = tag_input.trim();
+
+                let matching: Vec<&Task> = store
+                    .tasks
+                    .iter()
+                    .filter(|t| t.tags.iter().any(|existing| existing == tag))
+                    .collect();
+
+                if matching.is_empty() {
+                    println!("{}", format!("📝 No tasks tagged \"{tag}\".").yellow());
+                } else {
+                    print_tasks_table(&matching, &store.tasks);
+                }
+            }
+            "5" => {
+                if store.tasks.is_empty() {
+                    println!("{}", "📝 No tasks yet. Add one first.".yellow());
+                } else {
+                    let mut sorted: Vec<&Task> = store.tasks.iter().collect();
+                    sorted.sort_by_key(|t| std::cmp::Reverse(t.priority));
+                    print_tasks_table(&sorted, &store.tasks);
+                }
+            }
+            "6" => {
+                print!("{}", "Enter task ID to start: ".bright_yellow());
+                io::stdout().flush().unwrap();
+
+                let mut id_input = String::new();
+                io::stdin().read_line(&mut id_input).unwrap();
+
+                if let Ok(id) = id_input.trim().parse::<usize>() {
+                    if let Some(task) = store.tasks.iter().find(|t| t.id == id) {
+                        let open = task.open_dependencies(&store.tasks);
+                        if !open.is_empty() {
+                            let ids = open.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ");
+                            println!("{}", format!("❌ Task {id} is blocked on: {ids}").red());
+                        } else {
+                            let task = store.tasks.iter_mut().find(|t| t.id == id).unwrap();
+                            task.status = Status::Started;
+                            println!("🚀 Task {} started!", id);
+                            store.save();
+                        }
+                    } else {
+                        println!("❌ Task {} not found", id);
+                    }
+                } else {
+                    println!("❌ Invalid task ID");
+                }
+            }
+            "7" => {
+                print!("{}", "Enter task ID to return to inbox: ".bright_yellow());
+                io::stdout().flush().unwrap();
+
+                let mut id_input = String::new();
+                io::stdin().read_line(&mut id_input).unwrap();
+
+                if let Ok(id) = id_input.trim().parse::<usize>() {
+                    if let Some(task) = store.tasks.iter_mut().find(|t| t.id == id) {
+                        task.status = Status::Inbox;
+                        println!("📥 Task {} returned to inbox.", id);
+                        store.save();
+                    } else {
+                        println!("❌ Task {} not found", id);
+                    }
+                } else {
+                    println!("❌ Invalid task ID");
+                }
+            }
+            "8" => {
+                print!("{}", "Enter task ID to log time against: ".bright_yellow());
+                io::stdout().flush().unwrap();
+
+                let mut id_input = String::new();
+                io::stdin().read_line(&mut id_input).unwrap();
+
+                if let Ok(id) = id_input.trim().parse::<usize>() {
+                    if let Some(task) = store.tasks.iter_mut().find(|t| t.id == id) {
+                        print!("{}", "Minutes spent: ".bright_yellow());
+                        io::stdout().flush().unwrap();
+
+                        let mut minutes_input = String::new();
+                        io::stdin().read_line(&mut minutes_input).unwrap();
+
+                        if let Ok(total_minutes) = minutes_input.trim().parse::<u32>() {
+                            let entry = TimeEntry {
+                                logged_date: chrono::Local::now().date_naive(),
+                                hours: (total_minutes / 60) as u16,
+                                minutes: (total_minutes % 60) as u16,
+                            };
+                            task.time_entries.push(entry);
+                            println!("⏱️  Logged {total_minutes} minutes on task {id}.");
+                            store.save();
+                        } else {
+                            println!("❌ Invalid number of minutes");
+                        }
+                    } else {
+                        println!("❌ Task {} not found", id);
+                    }
+                } else {
+                    println!("❌ Invalid task ID");
+                }
+            }
+            "9" => {
+                print!("{}", "Enter task ID to add a dependency to: ".bright_yellow());
+                io::stdout().flush().unwrap();
+
+                let mut id_input = String::new();
+                io::stdin().read_line(&mut id_input).unwrap();
+
+                if let Ok(id) = id_input.trim().parse::<usize>() {
+                    if store.tasks.iter().any(|t| t.id == id) {
+                        print!("{}", "Depends on (task ID): ".bright_yellow());
+                        io::stdout().flush().unwrap();
+
+                        let mut dep_input = String::new();
+                        io::stdin().read_line(&mut dep_input).unwrap();
+
+                        match parse_dependency_input(&dep_input, id, &store.tasks) {
+                            Ok(new_deps) => {
+                                let task = store.tasks.iter_mut().find(|t| t.id == id).unwrap();
+                                task.dependencies.extend(new_deps);
+                                println!("🔗 Dependency added to task {id}.");
+                                store.save();
+                            }
+                            Err(e) => println!("❌ {e}"),
+                        }
+                    } else {
+                        println!("❌ Task {} not found", id);
+                    }
+                } else {
+                    println!("❌ Invalid task ID");
+                }
+            }
+            "10" => {
                 println!("{}", "👋 Goodbye!".bright_magenta());
                 break;
             }